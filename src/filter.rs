@@ -0,0 +1,109 @@
+//! This module contains the type alias for functions called as filters
+//! that transform a given input before validators run.
+//!
+//! Filters receive the raw input typed by the user and return a (possibly)
+//! transformed version of it. The transformed value is what gets passed to
+//! validators and ultimately returned as the answer.
+//!
+//! This module also provides a few built-in filters generated through
+//! macros, exported with the `builtin_validators` feature.
+//!
+//! # Status
+//!
+//! This source tree has no `with_filter` builder on [Text](crate::Text) and
+//! no call site that applies a [`Filter`] to a prompt's input: this module
+//! ships the `Filter` type and the built-in filters only, and running them
+//! before validation, in order, is left to the caller.
+
+/// Type alias for filters that receive a string slice as the input and
+/// return an owned, transformed `String`.
+///
+/// When a prompt has more than one filter, they run in the order they were
+/// added, each one receiving the previous filter's output.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::filter::Filter;
+///
+/// let filter: Filter = &|input| input.trim().to_string();
+/// assert_eq!(String::from("hello"), filter("  hello  "));
+/// ```
+pub type Filter<'a> = &'a dyn Fn(&str) -> String;
+
+/// Built-in filter that trims leading and trailing whitespace from the
+/// answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{trim, filter::Filter};
+///
+/// let filter: Filter = trim!();
+/// assert_eq!(String::from("hello"), filter("  hello  "));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! trim {
+    () => {
+        &|a: &str| a.trim().to_string()
+    };
+}
+
+/// Built-in filter that lowercases the answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{lowercase, filter::Filter};
+///
+/// let filter: Filter = lowercase!();
+/// assert_eq!(String::from("hello"), filter("HeLLo"));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! lowercase {
+    () => {
+        &|a: &str| a.to_lowercase()
+    };
+}
+
+/// Built-in filter that turns the answer into a URL/tag-friendly slug: runs
+/// of characters that aren't ASCII alphanumerics or `_` (dashes included)
+/// are collapsed into a single `-`, the result is lowercased, and any leading
+/// or trailing `-` is trimmed off.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{slug, filter::Filter};
+///
+/// let filter: Filter = slug!();
+/// assert_eq!(String::from("new-zealand-jan-2020"), filter("New Zealand, Jan 2020"));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! slug {
+    () => {
+        &|a: &str| {
+            let mut slug = String::with_capacity(a.len());
+            let mut last_was_dash = true;
+
+            for c in a.chars() {
+                if c.is_ascii_alphanumeric() || c == '_' {
+                    slug.push(c.to_ascii_lowercase());
+                    last_was_dash = false;
+                } else if !last_was_dash {
+                    slug.push('-');
+                    last_was_dash = true;
+                }
+            }
+
+            if slug.ends_with('-') {
+                slug.pop();
+            }
+
+            slug
+        }
+    };
+}
@@ -0,0 +1,107 @@
+//! This module contains the `History` trait and a ready-to-use ring-buffer
+//! implementation of it, letting prompts recall previously submitted
+//! answers when the user presses Up/Down.
+//!
+//! # Status
+//!
+//! This source tree has no `with_history` builder on [Text](crate::Text) or
+//! [CustomType](crate::CustomType) and no Up/Down key handling that calls
+//! `History::read`/`write`: this module ships the `History` trait and the
+//! `BasicHistory` implementation only.
+
+/// Trait for types that can record and recall previously submitted answers.
+///
+/// Implementors back the up/down recall behavior of [Text](crate::Text) and
+/// [CustomType](crate::CustomType) prompts.
+pub trait History {
+    /// Returns the entry `pos` steps back from the most recently written
+    /// one, where `pos == 0` is the most recent entry, or `None` if there's
+    /// no entry that far back.
+    fn read(&self, pos: usize) -> Option<String>;
+
+    /// Records a newly submitted answer.
+    fn write(&mut self, entry: &str);
+}
+
+/// Ring-buffer [`History`] implementation with an optional cap on the
+/// number of stored entries and optional de-duplication of consecutive
+/// repeats.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::history::{BasicHistory, History};
+///
+/// let mut history = BasicHistory::new().max_length(2).no_duplicates(true);
+/// history.write("first");
+/// history.write("second");
+/// history.write("second");
+/// history.write("third");
+///
+/// assert_eq!(Some(String::from("third")), history.read(0));
+/// assert_eq!(Some(String::from("second")), history.read(1));
+/// assert_eq!(None, history.read(2));
+/// ```
+pub struct BasicHistory {
+    entries: Vec<String>,
+    max_length: usize,
+    no_duplicates: bool,
+}
+
+impl BasicHistory {
+    /// Creates a new, empty history with no maximum length and duplicates
+    /// allowed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of entries kept in the history. Once the cap
+    /// is reached, the oldest entry is dropped to make room for a new one.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Sets whether a new entry equal to the most recent one should be
+    /// skipped instead of being written again.
+    pub fn no_duplicates(mut self, no_duplicates: bool) -> Self {
+        self.no_duplicates = no_duplicates;
+        self
+    }
+}
+
+impl Default for BasicHistory {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            max_length: usize::MAX,
+            no_duplicates: false,
+        }
+    }
+}
+
+impl History for BasicHistory {
+    fn read(&self, pos: usize) -> Option<String> {
+        self.entries
+            .len()
+            .checked_sub(pos + 1)
+            .and_then(|idx| self.entries.get(idx))
+            .cloned()
+    }
+
+    fn write(&mut self, entry: &str) {
+        if self.max_length == 0 {
+            return;
+        }
+
+        if self.no_duplicates && self.entries.last().map(String::as_str) == Some(entry) {
+            return;
+        }
+
+        if self.entries.len() >= self.max_length {
+            self.entries.remove(0);
+        }
+
+        self.entries.push(entry.to_string());
+    }
+}
@@ -0,0 +1,121 @@
+//! This module contains the type alias for suggester functions, plus a
+//! built-in fuzzy-matching scorer that can back a `with_suggester` call on
+//! [Text](crate::Text) prompts.
+
+use std::cmp::Reverse;
+
+/// Type alias for functions that, given the current input, return the list
+/// of suggestions to present to the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::suggester::Suggester;
+///
+/// let suggester: Suggester = &|input| {
+///     vec!["Becker PLC", "Barrows-Becker"]
+///         .into_iter()
+///         .filter(|s| s.to_lowercase().contains(&input.to_lowercase()))
+///         .map(String::from)
+///         .collect()
+/// };
+///
+/// assert_eq!(vec![String::from("Becker PLC")], suggester("Becker P"));
+/// ```
+pub type Suggester<'a> = &'a dyn Fn(&str) -> Vec<String>;
+
+/// Scores how well `candidate` matches `query` as a case-insensitive,
+/// in-order subsequence, the way fuzzy-finders (e.g. fzf) do.
+///
+/// Every character of `query` must appear in `candidate`, in the same
+/// order, for a match to be considered at all; returns `None` otherwise.
+/// Consecutive matches and matches that start a word (the first character,
+/// or the character right after a non-alphanumeric one) are rewarded, and
+/// gaps between matched characters are penalized, so tighter, more
+/// word-aligned matches sort first.
+pub fn fuzzy_score(candidate: &str, query: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let query_chars: Vec<char> = query.chars().collect();
+
+    let mut score: i64 = 0;
+    let mut query_idx = 0;
+    let mut last_match_idx: Option<usize> = None;
+
+    for (i, c) in candidate_chars.iter().enumerate() {
+        if query_idx >= query_chars.len() {
+            break;
+        }
+
+        if !c.to_lowercase().eq(query_chars[query_idx].to_lowercase()) {
+            continue;
+        }
+
+        let is_word_boundary = i == 0 || !candidate_chars[i - 1].is_alphanumeric();
+        let is_consecutive = last_match_idx.is_some_and(|last| last + 1 == i);
+
+        score += 10;
+        if is_word_boundary {
+            score += 8;
+        }
+        if is_consecutive {
+            score += 5;
+        }
+        if let Some(last) = last_match_idx {
+            score -= (i - last) as i64;
+        }
+
+        last_match_idx = Some(i);
+        query_idx += 1;
+    }
+
+    if query_idx == query_chars.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+/// Built-in suggester, for use with `with_suggester`, that ranks `options`
+/// against the current input using [`fuzzy_score`], dropping candidates
+/// that don't match at all and returning at most `page_size` of the
+/// best-scoring ones, best first.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::suggester::fuzzy_suggester;
+///
+/// let options = vec![
+///     String::from("Becker PLC"),
+///     String::from("Barrows-Becker"),
+///     String::from("Feil PLC"),
+/// ];
+///
+/// let suggester = fuzzy_suggester(options, 5);
+/// let suggestions = suggester("bck");
+///
+/// assert_eq!(
+///     vec![String::from("Becker PLC"), String::from("Barrows-Becker")],
+///     suggestions
+/// );
+/// ```
+pub fn fuzzy_suggester(options: Vec<String>, page_size: usize) -> impl Fn(&str) -> Vec<String> {
+    move |input: &str| {
+        let mut scored: Vec<(i64, &String)> = options
+            .iter()
+            .filter_map(|opt| fuzzy_score(opt, input).map(|score| (score, opt)))
+            .collect();
+
+        scored.sort_by_key(|b| Reverse(b.0));
+
+        scored
+            .into_iter()
+            .take(page_size)
+            .map(|(_, opt)| opt.clone())
+            .collect()
+    }
+}
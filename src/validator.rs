@@ -111,6 +111,42 @@ pub type DateValidator<'a> = &'a dyn Fn(chrono::NaiveDate) -> Result<(), String>
 /// ```
 pub type MultiOptionValidator<'a> = &'a dyn Fn(&[OptionAnswer]) -> Result<(), String>;
 
+/// Type alias for validators used in [CustomType](crate::CustomType) prompts,
+/// parameterized over the type `T` the raw input was parsed into.
+///
+/// Unlike [`StringValidator`], which only ever sees the string typed by the
+/// user, a `CustomTypeValidator` runs after parsing succeeds and receives the
+/// parsed value itself, so numeric constraints can be expressed directly in
+/// terms of `T` instead of being re-derived from its string representation.
+///
+/// If the parsed value is valid, your validator should return `Ok(())`.
+///
+/// If the value is not valid, your validator should return `Err(String)`,
+/// where the content of `Err` is a string whose content will be displayed
+/// to the user as an error message.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::CustomTypeValidator;
+///
+/// let validator: CustomTypeValidator<f64> = &|input| match *input > 0.0 {
+///     true => Ok(()),
+///     false => Err(String::from("Amount must be positive")),
+/// };
+///
+/// assert_eq!(Ok(()), validator(&12.5));
+/// assert_eq!(Err(String::from("Amount must be positive")), validator(&-1.0));
+/// ```
+///
+/// # Status
+///
+/// This source tree has no call site where [`CustomType`](crate::CustomType)
+/// accepts one of these and runs it after parsing: this alias and the
+/// macros built on it (`range!`, `min_value!`, `max_value!`, `multiple_of!`)
+/// are usable on their own, as in the example above.
+pub type CustomTypeValidator<'a, T> = &'a dyn Fn(&T) -> Result<(), String>;
+
 /// Built-in validator that checks whether the answer is not empty.
 ///
 /// # Arguments
@@ -152,6 +188,7 @@ macro_rules! required {
 /// Be careful when using this as a StringValidator. The `len()` method used
 /// in this validator is not the best tool for that. See this
 /// [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust)
+/// Prefer [`max_chars!`] if you want to count characters instead of bytes.
 ///
 /// # Arguments
 ///
@@ -196,6 +233,7 @@ macro_rules! max_length {
 /// Be careful when using this as a StringValidator. The `len()` method used
 /// in this validator is not the best tool for that. See this
 /// [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust)
+/// Prefer [`min_chars!`] if you want to count characters instead of bytes.
 ///
 /// # Arguments
 ///
@@ -239,6 +277,7 @@ macro_rules! min_length {
 /// Be careful when using this as a StringValidator. The `len()` method used
 /// in this validator is not the best tool for that. See this
 /// [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust)
+/// Prefer [`chars_length!`] if you want to count characters instead of bytes.
 ///
 /// # Arguments
 ///
@@ -273,3 +312,607 @@ macro_rules! length {
         }
     }};
 }
+
+/// Built-in validator that checks whether the answer char count is smaller
+/// than or equal to the specified threshold.
+///
+/// Unlike [`max_length!`], which counts bytes via `str::len()`, this counts
+/// Unicode scalar values via `str::chars().count()`, so it reports the length
+/// a user would expect for non-ASCII input.
+///
+/// # Arguments
+///
+/// * `$length` - Maximum number of characters allowed in the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The length of the response should be at most $length"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{max_chars, validator::StringValidator};
+///
+/// let validator: StringValidator = max_chars!(5);
+/// assert_eq!(Ok(()), validator("Good"));
+/// assert_eq!(Err(String::from("The length of the response should be at most 5")), validator("Terrible"));
+///
+/// let validator: StringValidator = max_chars!(3);
+/// assert_eq!(Ok(()), validator("日本語"));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! max_chars {
+    ($length:expr) => {
+        $crate::max_chars! {$length, format!("The length of the response should be at most {}", $length)}
+    };
+
+    ($length:expr, $message:expr) => {{
+        &|a: &str| match a.chars().count() {
+            _len if _len <= $length => Ok(()),
+            _ => Err(String::from($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer char count is larger
+/// than or equal to the specified threshold.
+///
+/// Unlike [`min_length!`], which counts bytes via `str::len()`, this counts
+/// Unicode scalar values via `str::chars().count()`, so it reports the length
+/// a user would expect for non-ASCII input.
+///
+/// # Arguments
+///
+/// * `$length` - Minimum number of characters required in the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The length of the response should be at least $length"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{min_chars, validator::StringValidator};
+///
+/// let validator: StringValidator = min_chars!(3);
+/// assert_eq!(Ok(()), validator("Yes"));
+/// assert_eq!(Err(String::from("The length of the response should be at least 3")), validator("No"));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! min_chars {
+    ($length:expr) => {
+        $crate::min_chars! {$length, format!("The length of the response should be at least {}", $length)}
+    };
+
+    ($length:expr, $message:expr) => {{
+        &|a: &str| match a.chars().count() {
+            _len if _len >= $length => Ok(()),
+            _ => Err(String::from($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer char count is equal to
+/// the specified value.
+///
+/// Unlike [`length!`], which counts bytes via `str::len()`, this counts
+/// Unicode scalar values via `str::chars().count()`, so a fixed-length code
+/// made of multi-byte characters is validated correctly.
+///
+/// # Arguments
+///
+/// * `$length` - Expected number of characters in the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The length of the response should be $length"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{chars_length, validator::StringValidator};
+///
+/// let validator: StringValidator = chars_length!(3);
+/// assert_eq!(Ok(()), validator("Yes"));
+/// assert_eq!(Ok(()), validator("日本語"));
+/// assert_eq!(Err(String::from("The length of the response should be 3")), validator("No"));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! chars_length {
+    ($length:expr) => {
+        $crate::chars_length! {$length, format!("The length of the response should be {}", $length)}
+    };
+
+    ($length:expr, $message:expr) => {{
+        &|a: &str| match a.chars().count() {
+            _len if _len == $length => Ok(()),
+            _ => Err(String::from($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is a syntactically valid
+/// e-mail address.
+///
+/// This only checks the basic shape of the address (a single `@`, a non-empty
+/// local part and a domain part containing at least one `.`, with no
+/// whitespace anywhere); it does not perform any DNS or mailbox verification.
+///
+/// # Arguments
+///
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "Please enter a valid email address."
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{email, validator::StringValidator};
+///
+/// let validator: StringValidator = email!();
+/// assert_eq!(Ok(()), validator("user@example.com"));
+/// assert_eq!(
+///     Err(String::from("Please enter a valid email address.")),
+///     validator("not-an-email")
+/// );
+/// assert_eq!(
+///     Err(String::from("Please enter a valid email address.")),
+///     validator("user@second@example.com")
+/// );
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! email {
+    () => {
+        $crate::email! {"Please enter a valid email address."}
+    };
+
+    ($message:expr) => {{
+        &|a: &str| {
+            let valid = a.matches('@').count() == 1
+                && match a.split_once('@') {
+                    Some((local, domain)) => {
+                        !local.is_empty()
+                            && !domain.is_empty()
+                            && domain.contains('.')
+                            && !domain.starts_with('.')
+                            && !domain.ends_with('.')
+                    }
+                    None => false,
+                }
+                && !a.contains(char::is_whitespace);
+
+            match valid {
+                true => Ok(()),
+                false => Err(String::from($message)),
+            }
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is a syntactically valid
+/// URL.
+///
+/// A URL is considered valid if it starts with a scheme (e.g. `http`,
+/// `https`, `ftp`) followed by `://` and a non-empty authority, with no
+/// whitespace anywhere in the string.
+///
+/// # Arguments
+///
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "Please enter a valid URL."
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{url, validator::StringValidator};
+///
+/// let validator: StringValidator = url!();
+/// assert_eq!(Ok(()), validator("https://example.com"));
+/// assert_eq!(
+///     Err(String::from("Please enter a valid URL.")),
+///     validator("example.com")
+/// );
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! url {
+    () => {
+        $crate::url! {"Please enter a valid URL."}
+    };
+
+    ($message:expr) => {{
+        &|a: &str| {
+            let valid = match a.split_once("://") {
+                Some((scheme, rest)) => {
+                    !scheme.is_empty()
+                        && scheme
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+                        && !rest.is_empty()
+                        && !a.contains(char::is_whitespace)
+                }
+                None => false,
+            };
+
+            match valid {
+                true => Ok(()),
+                false => Err(String::from($message)),
+            }
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is a valid IP address.
+///
+/// Accepts both IPv4 and IPv6 notations. Use `ip!(v4)` or `ip!(v6)` to
+/// restrict the validator to one of the two notations.
+///
+/// # Arguments
+///
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "Please enter a valid IP address."
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{ip, validator::StringValidator};
+///
+/// let validator: StringValidator = ip!();
+/// assert_eq!(Ok(()), validator("127.0.0.1"));
+/// assert_eq!(Ok(()), validator("::1"));
+/// assert_eq!(Err(String::from("Please enter a valid IP address.")), validator("not-an-ip"));
+///
+/// let validator: StringValidator = ip!(v4);
+/// assert_eq!(Ok(()), validator("127.0.0.1"));
+/// assert_eq!(Err(String::from("Please enter a valid IP address.")), validator("::1"));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! ip {
+    () => {
+        $crate::ip! {"Please enter a valid IP address."}
+    };
+
+    (v4) => {
+        $crate::ip! {v4, "Please enter a valid IP address."}
+    };
+
+    (v4, $message:expr) => {{
+        &|a: &str| match a.parse::<::std::net::Ipv4Addr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(String::from($message)),
+        }
+    }};
+
+    (v6) => {
+        $crate::ip! {v6, "Please enter a valid IP address."}
+    };
+
+    (v6, $message:expr) => {{
+        &|a: &str| match a.parse::<::std::net::Ipv6Addr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(String::from($message)),
+        }
+    }};
+
+    ($message:expr) => {{
+        &|a: &str| match a.parse::<::std::net::IpAddr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(String::from($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer matches the given
+/// regular expression.
+///
+/// The pattern is compiled once, the first time the validator is invoked,
+/// and the compiled [`Regex`](regex::Regex) is cached for every call after
+/// that.
+///
+/// # Arguments
+///
+/// * `$pattern` - Regular expression the answer must match.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "Your answer must match the pattern $pattern"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{regex, validator::StringValidator};
+///
+/// let validator: StringValidator = regex!(r"^\d{5}$");
+/// assert_eq!(Ok(()), validator("12345"));
+/// assert!(validator("1234").is_err());
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! regex {
+    ($pattern:expr) => {
+        $crate::regex! {$pattern, format!("Your answer must match the pattern {}", $pattern)}
+    };
+
+    ($pattern:expr, $message:expr) => {{
+        static RE: ::std::sync::OnceLock<::regex::Regex> = ::std::sync::OnceLock::new();
+
+        &|a: &str| {
+            let re = RE.get_or_init(|| {
+                ::regex::Regex::new($pattern).expect("invalid regex pattern passed to regex!")
+            });
+
+            match re.is_match(a) {
+                true => Ok(()),
+                false => Err(String::from($message)),
+            }
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is a valid credit card
+/// number, verified via the [Luhn algorithm](https://en.wikipedia.org/wiki/Luhn_algorithm).
+///
+/// Spaces and dashes in the input are ignored. Any other non-digit
+/// character, or a digit count outside the 12-19 range used by real card
+/// networks, makes the answer invalid.
+///
+/// # Arguments
+///
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "Please enter a valid credit card number."
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{card, validator::StringValidator};
+///
+/// let validator: StringValidator = card!();
+/// assert_eq!(Ok(()), validator("4539 1488 0343 6467"));
+/// assert_eq!(
+///     Err(String::from("Please enter a valid credit card number.")),
+///     validator("1234 5678 9012 3456")
+/// );
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! card {
+    () => {
+        $crate::card! {"Please enter a valid credit card number."}
+    };
+
+    ($message:expr) => {{
+        &|a: &str| {
+            let digits: Option<Vec<u32>> = a
+                .chars()
+                .filter(|c| *c != ' ' && *c != '-')
+                .map(|c| c.to_digit(10))
+                .collect();
+
+            let valid = match digits {
+                Some(digits) if (12..=19).contains(&digits.len()) => {
+                    let sum: u32 = digits
+                        .iter()
+                        .rev()
+                        .enumerate()
+                        .map(|(i, d)| {
+                            if i % 2 == 1 {
+                                let doubled = d * 2;
+                                if doubled > 9 {
+                                    doubled - 9
+                                } else {
+                                    doubled
+                                }
+                            } else {
+                                *d
+                            }
+                        })
+                        .sum();
+
+                    sum % 10 == 0
+                }
+                _ => false,
+            };
+
+            match valid {
+                true => Ok(()),
+                false => Err(String::from($message)),
+            }
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is within the given
+/// inclusive range.
+///
+/// Works with any type that implements `PartialOrd`, so it's usable with
+/// [`CustomType`](crate::CustomType) prompts over numbers, dates, or any
+/// other orderable value.
+///
+/// # Arguments
+///
+/// * `$min` - Lower bound of the allowed range, inclusive.
+/// * `$max` - Upper bound of the allowed range, inclusive.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The value must be between $min and $max"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{range, validator::CustomTypeValidator};
+///
+/// let validator: CustomTypeValidator<f64> = range!(0.0, 100.0);
+/// assert_eq!(Ok(()), validator(&50.0));
+/// assert_eq!(
+///     Err(String::from("The value must be between 0 and 100")),
+///     validator(&150.0)
+/// );
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! range {
+    ($min:expr, $max:expr) => {
+        $crate::range! {$min, $max, format!("The value must be between {} and {}", $min, $max)}
+    };
+
+    ($min:expr, $max:expr, $message:expr) => {{
+        &|a| match *a >= $min && *a <= $max {
+            true => Ok(()),
+            false => Err(String::from($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is greater than or
+/// equal to the given minimum.
+///
+/// # Arguments
+///
+/// * `$min` - Minimum value allowed, inclusive.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The value must be at least $min"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{min_value, validator::CustomTypeValidator};
+///
+/// let validator: CustomTypeValidator<f64> = min_value!(0.0, "Amount must be positive");
+/// assert_eq!(Ok(()), validator(&12.5));
+/// assert_eq!(Err(String::from("Amount must be positive")), validator(&-1.0));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! min_value {
+    ($min:expr) => {
+        $crate::min_value! {$min, format!("The value must be at least {}", $min)}
+    };
+
+    ($min:expr, $message:expr) => {{
+        &|a| match *a >= $min {
+            true => Ok(()),
+            false => Err(String::from($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is smaller than or
+/// equal to the given maximum.
+///
+/// # Arguments
+///
+/// * `$max` - Maximum value allowed, inclusive.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The value must be at most $max"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{max_value, validator::CustomTypeValidator};
+///
+/// let validator: CustomTypeValidator<f64> = max_value!(100.0);
+/// assert_eq!(Ok(()), validator(&50.0));
+/// assert_eq!(Err(String::from("The value must be at most 100")), validator(&150.0));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! max_value {
+    ($max:expr) => {
+        $crate::max_value! {$max, format!("The value must be at most {}", $max)}
+    };
+
+    ($max:expr, $message:expr) => {{
+        &|a| match *a <= $max {
+            true => Ok(()),
+            false => Err(String::from($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer is a multiple of the
+/// given step.
+///
+/// Works with any numeric type that converts losslessly into `f64` via
+/// `Into<f64>` (e.g. `f32`, `f64`, and the integer types up to 32 bits).
+/// Rather than comparing `a % multiple` against zero directly — which is
+/// unreliable for floats, since e.g. `19.99_f64 % 0.01` is
+/// `0.009999999999998021`, not `0.0` — this rounds `a / multiple` to the
+/// nearest integer and accepts the answer if the two are within a small
+/// epsilon of each other.
+///
+/// # Arguments
+///
+/// * `$multiple` - Step the answer must be a multiple of.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The value must be a multiple of $multiple"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{multiple_of, validator::CustomTypeValidator};
+///
+/// let validator: CustomTypeValidator<f64> = multiple_of!(0.01);
+/// assert_eq!(Ok(()), validator(&19.99));
+/// assert_eq!(
+///     Err(String::from("The value must be a multiple of 0.01")),
+///     validator(&19.994)
+/// );
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! multiple_of {
+    ($multiple:expr) => {
+        $crate::multiple_of! {$multiple, format!("The value must be a multiple of {}", $multiple)}
+    };
+
+    ($multiple:expr, $message:expr) => {{
+        const EPSILON: f64 = 1e-9;
+
+        &|a| {
+            let num: f64 = (*a).into();
+            let quotient = num / f64::from($multiple);
+
+            match (quotient - quotient.round()).abs() < EPSILON {
+                true => Ok(()),
+                false => Err(String::from($message)),
+            }
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer matches another,
+/// previously obtained value — the building block for "confirm password"
+/// or "re-enter amount" style flows, where one prompt's answer has to be
+/// cross-checked against a sibling prompt's answer.
+///
+/// Since a [`StringValidator`] closure only ever sees the input of the
+/// prompt it's attached to, capture the expected value by reference before
+/// building the second prompt and compare against it when that prompt is
+/// validated.
+///
+/// # Arguments
+///
+/// * `$expected` - Value the answer is compared against.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The two entries don't match."
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{must_match, validator::StringValidator};
+///
+/// let original = String::from("hunter2");
+/// let validator: StringValidator = must_match!(&original);
+///
+/// assert_eq!(Ok(()), validator("hunter2"));
+/// assert_eq!(Err(String::from("The two entries don't match.")), validator("hunter3"));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! must_match {
+    ($expected:expr) => {
+        $crate::must_match! {$expected, "The two entries don't match."}
+    };
+
+    ($expected:expr, $message:expr) => {
+        &|a: &str| match a == $expected {
+            true => Ok(()),
+            false => Err(String::from($message)),
+        }
+    };
+}